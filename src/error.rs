@@ -1,4 +1,4 @@
-use crate::Range;
+use crate::range::RutRange;
 use core::fmt;
 use std::error;
 
@@ -6,7 +6,7 @@ use std::error;
 pub enum Error {
     InvalidDV { must_be: char, instead: char },
     InvalidFormat,
-    OutOfRange,
+    OutOfRange { range: RutRange },
 }
 
 impl error::Error for Error {}
@@ -18,12 +18,9 @@ impl fmt::Display for Error {
             Error::InvalidDV { must_be, instead } => {
                 write!(f, "Invalid DV, must be {}, instead {}.", must_be, instead)
             }
-            Error::OutOfRange => write!(
-                f,
-                "The input number must be between {} to {}",
-                Range::MIN.to_string(),
-                Range::MAX.to_string()
-            ),
+            Error::OutOfRange { range } => {
+                write!(f, "The input number must be between {}", range)
+            }
         }
     }
 }
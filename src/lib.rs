@@ -47,6 +47,14 @@ DV: 7
 RUT: 17951585-7
 ```
 
+`Rut` also implements `FromStr`, so the standard `.parse()` method works too and plugs `Rut` into any generic code that expects it (clap args, serde string coercion, config files):
+
+```rust
+use rut_lib::Rut;
+
+let rut: Rut = "17951585-7".parse().unwrap();
+```
+
 #### Error behaviour
 <details><summary>Error::InvalidFormat</summary>
 <p>
@@ -145,6 +153,20 @@ Error: The input number must be between 1.000.000 to 99.999.999
 </p>
 </details>
 
+### Custom range
+`from_number` validates against `RutRange::DEFAULT`. To validate institutional RUTs or restrict to a narrower band, build a `RutRange` and use `from_number_in_range` instead:
+
+```rust
+use rut_lib::{Rut, RutRange};
+
+let range = RutRange::new(100_000_000, 999_999_999);
+
+match Rut::from_number_in_range(241_367_738, &range) {
+    Ok(rut) => println!("RUT: {:#}", rut),
+    Err(error) => println!("Error: {:#}", error)
+}
+```
+
 ## Randomize Rut
 Generate a randomize rut from scratch for testing use
 
@@ -187,18 +209,54 @@ Dots: 17.951.585-7
 Dash: 17951585-7
 None: 179515857
 ```
+
+## Serde support
+Enable the `serde` feature to make `Rut` a first-class field type in JSON/TOML/YAML structs. `Rut` serializes to the canonical dashed string and deserializes from any of the three input shapes, running full DV validation along the way.
+
+```toml
+[dependencies]
+rut-lib = { version = "1.0.0", features = ["serde"] }
+```
+
+```rust,ignore
+use rut_lib::Rut;
+use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize)]
+struct Person {
+    rut: Rut,
+}
+```
+
+To serialize with a different `Format`, use the `serialize_dots` helper with `serialize_with`:
+
+```rust,ignore
+#[derive(Serialize)]
+struct Person {
+    #[serde(serialize_with = "rut_lib::serialize_dots")]
+    rut: Rut,
+}
+```
 */
 
 mod error;
 mod range;
+#[cfg(feature = "serde")]
+mod serde_support;
 mod utils;
 
+#[cfg(feature = "serde")]
+pub use serde_support::serialize_dots;
+
 use core::fmt;
+use core::str::FromStr;
 use error::Error;
 use num_format::{Locale, ToFormattedString};
-use range::{random_number, Range};
-use regex::Regex;
-use utils::{mod_eleven, sum_product, PATTERN};
+use range::{random_number, random_number_with};
+use std::collections::HashSet;
+use utils::{extract, mod_eleven, sum_product};
+
+pub use range::RutRange;
 
 #[derive(Debug)]
 pub struct Rut {
@@ -219,31 +277,46 @@ impl fmt::Display for Rut {
     }
 }
 
+impl FromStr for Rut {
+    type Err = Error;
+
+    /// Parse a `Rut` from a input String, following the same
+    /// `extract_from`/`check_dv` pipeline as `Rut::from`.
+    fn from_str(input: &str) -> Result<Rut, Error> {
+        Rut::extract_from(input).and_then(Rut::check_dv)
+    }
+}
+
 impl Rut {
     /// Create a `Rut` from a input String
     /// This is useful when you want to parse to String
     /// or check if is a valid input Rut.
     ///
     /// The Input must be a valid RUT format.
+    ///
+    /// This is a thin wrapper over the `FromStr` implementation,
+    /// kept for backward compatibility.
     pub fn from(input: &str) -> Result<Rut, Error> {
-        match Rut::extract_from(input) {
-            Ok(unverified_rut) => Rut::check_dv(unverified_rut),
-            Err(error) => Err(error),
-        }
+        input.parse()
     }
 
     /// Create a `Rut` from a input Number
     ///
     /// The input must be between in a Range value.
     pub fn from_number(number: u32) -> Result<Rut, Error> {
-        let min = Range::MIN.to_u32();
-        let max = Range::MAX.to_u32();
-        let range = min..max;
-        if range.contains(&number) {
+        Rut::from_number_in_range(number, &RutRange::DEFAULT)
+    }
+
+    /// Create a `Rut` from a input Number, validating it against a
+    /// caller-chosen `RutRange` instead of the default natural-person
+    /// span. Useful to validate institutional RUTs or restrict to a
+    /// narrower custom band.
+    pub fn from_number_in_range(number: u32, range: &RutRange) -> Result<Rut, Error> {
+        if range.contains(number) {
             let dv = Rut::generate_dv(number);
             Ok(Rut { number, dv })
         } else {
-            Err(Error::OutOfRange)
+            Err(Error::OutOfRange { range: *range })
         }
     }
 
@@ -255,6 +328,49 @@ impl Rut {
         Rut { number, dv }
     }
 
+    /// Generate a Rut from scratch with a random number drawn from
+    /// the caller's RNG, e.g. a `StdRng::seed_from_u64(seed)` to
+    /// deterministically reproduce the same RUT across runs.
+    pub fn randomize_with<R: rand::Rng>(rng: &mut R) -> Rut {
+        let number = random_number_with(rng);
+        let dv = Rut::generate_dv(number);
+
+        Rut { number, dv }
+    }
+
+    /// Generate up to `count` distinct random `Rut`s, guaranteeing
+    /// no two share the same `number` (draw-without-replacement).
+    ///
+    /// If `count` exceeds the amount of distinct numbers in the valid
+    /// `RutRange::DEFAULT`, the returned `Vec` saturates at that cardinality
+    /// instead of looping forever looking for one more number.
+    pub fn randomize_many(count: usize) -> Vec<Rut> {
+        let mut rng = rand::thread_rng();
+        let max_count = RutRange::DEFAULT.cardinality();
+        let count = count.min(max_count);
+
+        let mut seen = HashSet::with_capacity(count);
+        let mut ruts = Vec::with_capacity(count);
+
+        while ruts.len() < count {
+            let number = random_number_with(&mut rng);
+            if seen.insert(number) {
+                let dv = Rut::generate_dv(number);
+                ruts.push(Rut { number, dv });
+            }
+        }
+
+        ruts
+    }
+
+    /// Return an infinite iterator yielding freshly randomized `Rut`s.
+    ///
+    /// Unlike `randomize_many`, this does not guarantee uniqueness
+    /// across the stream; it's the lazy counterpart of `randomize()`.
+    pub fn randomize_iter() -> impl Iterator<Item = Rut> {
+        std::iter::repeat_with(Rut::randomize)
+    }
+
     /// Take a `Rut` and Prettify the output to String
     /// This use the `Format` enum as input.
     pub fn to_format(&self, format: Format) -> String {
@@ -280,7 +396,7 @@ impl Rut {
     }
 
     fn check_dv(unsigned_rut: Rut) -> Result<Rut, Error> {
-        let signed_rut = Rut::from_number(unsigned_rut.number).unwrap();
+        let signed_rut = Rut::from_number(unsigned_rut.number)?;
         if unsigned_rut.dv != signed_rut.dv {
             Err(Error::InvalidDV {
                 must_be: signed_rut.dv,
@@ -292,15 +408,8 @@ impl Rut {
     }
 
     fn extract_from(input: &str) -> Result<Rut, Error> {
-        let regex = Regex::new(PATTERN).unwrap();
-        if regex.is_match(input) {
-            let captures = regex.captures(input).unwrap();
-            let number: u32 = captures["number"].replace(".", "").parse().unwrap();
-            let dv = captures["dv"].to_uppercase().chars().next().unwrap();
-            Ok(Rut { number, dv })
-        } else {
-            Err(Error::InvalidFormat)
-        }
+        let (number, dv) = extract(input)?;
+        Ok(Rut { number, dv })
     }
 
     fn generate_dv(number: u32) -> char {
@@ -369,6 +478,65 @@ mod rut_test {
         assert_eq!(rut.to_string(), rut.to_string())
     }
 
+    #[test]
+    fn from_str() {
+        let rut: Rut = "17951585-7".parse().unwrap();
+        assert_eq!(rut.number(), &17951585);
+        assert_eq!(rut.dv(), &'7');
+
+        let error: Error = "17951585-K".parse::<Rut>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            Error::InvalidDV {
+                must_be: '7',
+                instead: 'K'
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn from_str_leading_zero_out_of_range() {
+        // A format-valid number with a leading zero can parse below
+        // `RutRange::DEFAULT`; this must surface as `Err`, not panic.
+        let error = "0123456-6".parse::<Rut>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            Error::OutOfRange {
+                range: RutRange::DEFAULT
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn randomize_with() {
+        use rand::SeedableRng;
+
+        let mut rng_a = rand::rngs::StdRng::seed_from_u64(42);
+        let mut rng_b = rand::rngs::StdRng::seed_from_u64(42);
+
+        let rut_a = Rut::randomize_with(&mut rng_a);
+        let rut_b = Rut::randomize_with(&mut rng_b);
+
+        assert_eq!(rut_a.to_string(), rut_b.to_string())
+    }
+
+    #[test]
+    fn randomize_many() {
+        let ruts = Rut::randomize_many(50);
+        assert_eq!(ruts.len(), 50);
+
+        let numbers: HashSet<&u32> = ruts.iter().map(Rut::number).collect();
+        assert_eq!(numbers.len(), 50)
+    }
+
+    #[test]
+    fn randomize_iter() {
+        let ruts: Vec<Rut> = Rut::randomize_iter().take(10).collect();
+        assert_eq!(ruts.len(), 10)
+    }
+
     #[test]
     fn wrong_dv() {
         assert_eq!(
@@ -393,11 +561,33 @@ mod rut_test {
     fn out_of_range() {
         assert_eq!(
             Rut::from_number(999999).unwrap_err().to_string(),
-            Error::OutOfRange.to_string()
+            Error::OutOfRange {
+                range: RutRange::DEFAULT
+            }
+            .to_string()
         );
         assert_eq!(
             Rut::from_number(100000000).unwrap_err().to_string(),
-            Error::OutOfRange.to_string()
+            Error::OutOfRange {
+                range: RutRange::DEFAULT
+            }
+            .to_string()
         );
     }
+
+    #[test]
+    fn from_number_in_range() {
+        let range = RutRange::new(100_000_000, 200_000_000);
+
+        assert!(Rut::from_number_in_range(150_000_000, &range).is_ok());
+        assert!(Rut::from_number_in_range(range.max, &range).is_ok());
+
+        let error = Rut::from_number_in_range(1, &range).unwrap_err();
+        assert_eq!(error.to_string(), Error::OutOfRange { range }.to_string())
+    }
+
+    #[test]
+    fn from_number_reaches_upper_bound() {
+        assert!(Rut::from_number(RutRange::DEFAULT.max).is_ok())
+    }
 }
@@ -2,34 +2,77 @@ use core::fmt;
 use num_format::{Locale, ToFormattedString};
 use rand::prelude::*;
 
-pub enum Range {
-    MIN = 1_000_000,
-    MAX = 99_999_999,
+/// An inclusive `[min, max]` interval of valid RUT numbers.
+///
+/// `Rut::from_number` and `Rut::randomize` validate and draw against
+/// `RutRange::DEFAULT`; pass a custom `RutRange` to
+/// `Rut::from_number_in_range` to validate institutional RUTs or
+/// restrict to a narrower custom band.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RutRange {
+    pub min: u32,
+    pub max: u32,
 }
 
-impl fmt::Display for Range {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.to_u32().to_formatted_string(&Locale::es_CL))
+impl RutRange {
+    /// The default range used throughout the crate: natural-person
+    /// RUTs from `1.000.000` to `99.999.999`.
+    pub const DEFAULT: RutRange = RutRange {
+        min: 1_000_000,
+        max: 99_999_999,
+    };
+
+    /// Build a custom range to validate a number against.
+    pub fn new(min: u32, max: u32) -> RutRange {
+        RutRange { min, max }
+    }
+
+    pub(crate) fn contains(&self, number: u32) -> bool {
+        (self.min..=self.max).contains(&number)
+    }
+
+    /// Draw a number from this inclusive range using the caller's RNG.
+    ///
+    /// `rng.gen_range(low, high)` samples `[low, high)`, so `max` is
+    /// bumped by one to keep the draw consistent with `contains`.
+    pub(crate) fn sample<R: Rng>(&self, rng: &mut R) -> u32 {
+        rng.gen_range(self.min, self.max + 1)
+    }
+
+    /// The amount of distinct numbers this inclusive range admits.
+    pub(crate) fn cardinality(&self) -> usize {
+        (self.max - self.min + 1) as usize
     }
 }
 
-impl Range {
-    pub fn to_u32(&self) -> u32 {
-        match self {
-            Range::MIN => Range::MIN as u32,
-            Range::MAX => Range::MAX as u32,
-        }
+impl Default for RutRange {
+    fn default() -> Self {
+        RutRange::DEFAULT
     }
 }
 
-fn random_range(min: u32, max: u32) -> u32 {
-    let mut rand = rand::thread_rng();
-    rand.gen_range(min, max)
+impl fmt::Display for RutRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} to {}",
+            self.min.to_formatted_string(&Locale::es_CL),
+            self.max.to_formatted_string(&Locale::es_CL)
+        )
+    }
 }
 
 #[inline(always)]
 pub(crate) fn random_number() -> u32 {
-    random_range(Range::MIN as u32, Range::MAX as u32)
+    let mut rng = rand::thread_rng();
+    random_number_with(&mut rng)
+}
+
+/// Draw a number from `RutRange::DEFAULT` using the caller's RNG,
+/// so callers can seed a `StdRng` for reproducible output.
+#[inline(always)]
+pub(crate) fn random_number_with<R: Rng>(rng: &mut R) -> u32 {
+    RutRange::DEFAULT.sample(rng)
 }
 
 #[cfg(test)]
@@ -38,7 +81,31 @@ mod range_test {
 
     #[test]
     fn format() {
-        assert_eq!(Range::MIN.to_string(), "1.000.000");
-        assert_eq!(Range::MAX.to_string(), "99.999.999")
+        assert_eq!(RutRange::DEFAULT.to_string(), "1.000.000 to 99.999.999")
+    }
+
+    #[test]
+    fn contains() {
+        let range = RutRange::new(100, 200);
+        assert!(range.contains(150));
+        assert!(range.contains(100));
+        assert!(range.contains(200));
+        assert!(!range.contains(99));
+        assert!(!range.contains(201));
+    }
+
+    #[test]
+    fn sample_can_reach_max() {
+        let range = RutRange::new(100, 100);
+        let mut rng = rand::thread_rng();
+
+        assert_eq!(range.sample(&mut rng), 100);
+        assert!(range.contains(range.sample(&mut rng)));
+    }
+
+    #[test]
+    fn cardinality_is_inclusive() {
+        assert_eq!(RutRange::new(100, 200).cardinality(), 101);
+        assert_eq!(RutRange::new(100, 100).cardinality(), 1);
     }
 }
@@ -0,0 +1,84 @@
+use crate::{Format, Rut};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+impl Serialize for Rut {
+    /// Serialize as the canonical dashed string, e.g. `17951585-7`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_format(Format::DASH))
+    }
+}
+
+struct RutVisitor;
+
+impl<'de> Visitor<'de> for RutVisitor {
+    type Value = Rut;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a RUT string, e.g. `17.951.585-7`, `17951585-7` or `179515857`")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Rut, E>
+    where
+        E: de::Error,
+    {
+        Rut::from_str(value).map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for Rut {
+    /// Accept any of the three input shapes `Rut::from_str` handles,
+    /// running full DV validation before the value is accepted.
+    fn deserialize<D>(deserializer: D) -> Result<Rut, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(RutVisitor)
+    }
+}
+
+/// `serialize_with` helper to emit a `Rut` in `Format::DOTS` instead of
+/// the default dashed string, e.g. `#[serde(serialize_with = "rut_lib::serialize_dots")]`.
+pub fn serialize_dots<S>(rut: &Rut, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&rut.to_format(Format::DOTS))
+}
+
+#[cfg(test)]
+mod serde_support_test {
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        let rut = Rut::from_number(17951585).unwrap();
+        assert_eq!(serde_json::to_string(&rut).unwrap(), "\"17951585-7\"")
+    }
+
+    #[test]
+    fn deserialize() {
+        let rut: Rut = serde_json::from_str("\"17.951.585-7\"").unwrap();
+        assert_eq!(rut.number(), &17951585);
+    }
+
+    #[test]
+    fn deserialize_invalid_dv() {
+        let error = serde_json::from_str::<Rut>("\"17951585-K\"").unwrap_err();
+        assert!(error.to_string().contains("Invalid DV"));
+    }
+
+    #[test]
+    fn deserialize_leading_zero_out_of_range() {
+        // A malformed-but-grammar-valid leading-zero number must be
+        // rejected as a `serde::de::Error`, never panic, since this
+        // path is reachable from untrusted JSON/TOML/YAML input.
+        let error = serde_json::from_str::<Rut>("\"0123456-6\"").unwrap_err();
+        assert!(error.to_string().contains("between"));
+    }
+}
@@ -1,5 +1,4 @@
-pub(crate) const PATTERN: &str =
-    r"^(?P<number>\d{1,2}(?:\.)?\d{3}(?:\.)?\d{3})(?:-)?(?P<dv>(?i)K|\d)$";
+use crate::error::Error;
 
 #[derive(Clone, Copy)]
 enum Range {
@@ -43,6 +42,66 @@ pub(crate) fn sum_product(number: u32) -> u8 {
     total
 }
 
+/// Try to read the whole `number-dv` grammar out of `bytes`, assuming
+/// the first digit group is `first_len` digits long (1 or 2). Returns
+/// `None` on any mismatch so the caller can retry with the other length.
+fn try_parse(bytes: &[u8], first_len: usize) -> Option<(u32, char)> {
+    let mut i = 0;
+    let mut number: u32 = 0;
+
+    let mut read_digits = |i: &mut usize, count: usize| -> Option<()> {
+        for _ in 0..count {
+            let byte = *bytes.get(*i)?;
+            if !byte.is_ascii_digit() {
+                return None;
+            }
+            number = number * 10 + (byte - b'0') as u32;
+            *i += 1;
+        }
+        Some(())
+    };
+
+    read_digits(&mut i, first_len)?;
+    if bytes.get(i) == Some(&b'.') {
+        i += 1;
+    }
+    read_digits(&mut i, 3)?;
+    if bytes.get(i) == Some(&b'.') {
+        i += 1;
+    }
+    read_digits(&mut i, 3)?;
+
+    if bytes.get(i) == Some(&b'-') {
+        i += 1;
+    }
+
+    let dv = match bytes.get(i)? {
+        byte if byte.is_ascii_digit() => *byte as char,
+        b'K' | b'k' => 'K',
+        _ => return None,
+    };
+    i += 1;
+
+    if i != bytes.len() {
+        return None;
+    }
+
+    Some((number, dv))
+}
+
+/// Parse a `number-dv` pair out of a RUT string without a regex engine:
+/// a zero-allocation scanner over the grammar `\d{1,2}(\.)?\d{3}(\.)?\d{3}(-)?[0-9Kk]`.
+pub(crate) fn extract(input: &str) -> Result<(u32, char), Error> {
+    if !input.is_ascii() {
+        return Err(Error::InvalidFormat);
+    }
+    let bytes = input.as_bytes();
+
+    try_parse(bytes, 2)
+        .or_else(|| try_parse(bytes, 1))
+        .ok_or(Error::InvalidFormat)
+}
+
 #[cfg(test)]
 mod utils_test {
     use crate::utils::*;
@@ -67,4 +126,36 @@ mod utils_test {
         assert_eq!(limit_range(8), 2);
         assert_eq!(limit_range(2), 2);
     }
+
+    #[test]
+    fn test_extract() {
+        let valid_rut = [
+            ("17951585-7", 17951585, '7'),
+            ("5.665.328-7", 5665328, '7'),
+            ("241367738", 24136773, '8'),
+            ("17951585K", 17951585, 'K'),
+            ("17951585k", 17951585, 'K'),
+        ];
+
+        for (input, number, dv) in valid_rut.iter() {
+            assert_eq!(extract(input).unwrap(), (*number, *dv))
+        }
+    }
+
+    #[test]
+    fn test_extract_invalid_format() {
+        let invalid_rut = ["17.951,585-7", "17,951,585-7", "179515", "17951585K7"];
+        for input in invalid_rut.iter() {
+            assert!(extract(input).is_err())
+        }
+    }
+
+    #[test]
+    fn test_extract_leading_zero() {
+        // The grammar only cares about digit groups, not their value, so a
+        // leading zero is extracted like any other digit: the number it
+        // produces can fall below `RutRange::DEFAULT`, but rejecting that
+        // is `Rut::from_number`'s job, not this scanner's.
+        assert_eq!(extract("0123456-6").unwrap(), (123456, '6'))
+    }
 }